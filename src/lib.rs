@@ -1,8 +1,12 @@
 use std::fmt::Debug;
+use std::rc::Rc;
 
 use serde::{de::DeserializeOwned, Deserialize, Serialize};
 use sycamore::{futures::spawn_local_scoped, prelude::*};
-use wasm_cookies::CookieOptions;
+
+mod storage;
+pub use storage::Backend;
+use storage::StorageBackend;
 
 #[derive(Clone, Copy, PartialEq, Eq, Debug, Serialize, Deserialize)]
 pub enum ToastType {
@@ -12,12 +16,48 @@ pub enum ToastType {
     Danger,
 }
 
+/// Default time a toast stays on screen before auto-dismissing, in milliseconds.
+const DEFAULT_DURATION_MS: u32 = 5000;
+/// Granularity of the pause-aware countdown loop, in milliseconds.
+const DURATION_TICK_MS: u32 = 100;
+
+/// A single action button rendered on a [`Toast`], e.g. "Undo" or "Retry". Clicking it invokes
+/// `callback` and then dismisses the toast.
+///
+/// Actions are not persisted: since a callback can't be serialized, the `actions` field on
+/// `Toast` is skipped by serde and comes back empty after a reload.
+#[derive(Clone)]
+pub struct ToastAction {
+    label: String,
+    callback: Rc<dyn Fn()>,
+}
+
+impl Debug for ToastAction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ToastAction")
+            .field("label", &self.label)
+            .finish()
+    }
+}
+
+impl PartialEq for ToastAction {
+    fn eq(&self, other: &Self) -> bool {
+        self.label == other.label && Rc::ptr_eq(&self.callback, &other.callback)
+    }
+}
+
+impl Eq for ToastAction {}
+
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Toast {
     title: String,
     body: String,
     toast_type: ToastType,
     id: uuid::Uuid,
+    duration_ms: u32,
+    sticky: bool,
+    #[serde(skip)]
+    actions: Vec<ToastAction>,
 }
 
 impl Default for Toast {
@@ -27,6 +67,9 @@ impl Default for Toast {
             body: Default::default(),
             toast_type: ToastType::Primary,
             id: uuid::Uuid::new_v4(),
+            duration_ms: DEFAULT_DURATION_MS,
+            sticky: false,
+            actions: Vec::new(),
         }
     }
 }
@@ -35,36 +78,31 @@ impl Toast {
     pub fn primary<T: ToString>(text: T) -> Self {
         Self {
             title: text.to_string(),
-            body: String::new(),
-            toast_type: ToastType::Primary,
-            id: uuid::Uuid::new_v4(),
+            ..Default::default()
         }
     }
 
     pub fn success<T: ToString>(text: T) -> Self {
         Self {
             title: text.to_string(),
-            body: String::new(),
             toast_type: ToastType::Success,
-            id: uuid::Uuid::new_v4(),
+            ..Default::default()
         }
     }
 
     pub fn warning<T: ToString>(text: T) -> Self {
         Self {
             title: text.to_string(),
-            body: String::new(),
             toast_type: ToastType::Warning,
-            id: uuid::Uuid::new_v4(),
+            ..Default::default()
         }
     }
 
     pub fn danger<T: ToString>(text: T) -> Self {
         Self {
             title: text.to_string(),
-            body: String::new(),
             toast_type: ToastType::Danger,
-            id: uuid::Uuid::new_v4(),
+            ..Default::default()
         }
     }
 
@@ -72,11 +110,39 @@ impl Toast {
         self.body = body.to_string();
         self
     }
+
+    /// Overrides how long this toast stays on screen before auto-dismissing, in milliseconds.
+    pub fn duration(mut self, ms: u32) -> Self {
+        self.duration_ms = ms;
+        self
+    }
+
+    /// Marks this toast as never auto-dismissing; it can only be closed via the close button or
+    /// one of its action buttons (or programmatically).
+    pub fn sticky(mut self) -> Self {
+        self.sticky = true;
+        self
+    }
+
+    /// Adds an action button (e.g. "Undo" or "Retry") rendered between the body text and the
+    /// close button. Clicking it invokes `callback` and then dismisses the toast.
+    pub fn action<T: ToString>(mut self, label: T, callback: impl Fn() + 'static) -> Self {
+        self.actions.push(ToastAction {
+            label: label.to_string(),
+            callback: Rc::new(callback),
+        });
+        self
+    }
 }
 
+/// Note: `Toasts::default()` (and any other construction that doesn't specify a `Backend`)
+/// persists to `localStorage`, not cookies. Before `with_storage` existed, every `Toasts`
+/// unconditionally persisted to a cookie; if you're upgrading and relied on that, construct with
+/// `Toasts::with_storage(Backend::Cookie)` to keep toasts surviving in the same place.
 #[derive(Default, Debug, Clone)]
 pub struct Toasts<T: Clone + Debug + Default + Serialize + DeserializeOwned + 'static> {
     toasts: Signal<Vec<(T, u8)>>,
+    backend: Backend,
 }
 
 pub enum CookieError {
@@ -91,22 +157,37 @@ impl<T: Clone + Debug + Default + Serialize + DeserializeOwned> Toasts<T> {
                 toasts: create_signal(
                     serde_json::from_str(&c).map_err(|_| CookieError::InvalidCookie)?,
                 ),
+                backend: Backend::Cookie,
             })
         } else {
             Err(CookieError::CookieNotPresent)
         }
     }
 
+    /// Creates a new `Toasts`, restoring any toasts already persisted on the given `backend`
+    /// (or starting empty if none are stored yet).
+    pub fn with_storage(backend: Backend) -> Self {
+        let toasts = backend
+            .storage()
+            .load()
+            .and_then(|raw| serde_json::from_str(&raw).ok())
+            .unwrap_or_default();
+        Self {
+            toasts: create_signal(toasts),
+            backend,
+        }
+    }
+
     pub fn clear_toasts(&self) {
         self.toasts.update(|i| i.retain(|(_, r)| *r >= 1));
         self.toasts
             .update(|i| i.iter_mut().for_each(|(_, i)| *i -= 1));
-        self.save_to_cookies();
+        self.persist();
     }
 
     pub fn add_toast(&self, toast: T) -> &Self {
         self.toasts.update(|i| i.push((toast, 0)));
-        self.save_to_cookies();
+        self.persist();
         self
     }
 
@@ -116,20 +197,148 @@ impl<T: Clone + Debug + Default + Serialize + DeserializeOwned> Toasts<T> {
                 *r = rank;
             }
         });
-        self.save_to_cookies();
+        self.persist();
         self
     }
 
-    fn save_to_cookies(&self) {
-        // Save to cookies
-        wasm_cookies::set(
-            "sycamore_toasts",
-            &serde_json::to_string(&self.toasts.get_clone_untracked()).unwrap(),
-            &CookieOptions::default(),
-        );
+    fn persist(&self) {
+        self.backend
+            .storage()
+            .save(&serde_json::to_string(&self.toasts.get_clone_untracked()).unwrap());
+    }
+
+    /// A reactive count of the toasts actually rendered on screen, e.g. for a badge or to
+    /// suppress new toasts once a threshold is reached. Pass the same `max_visible` given to
+    /// [`ToastsView`] so this matches what it renders (rank 0 toasts, capped at `max_visible`);
+    /// pass `usize::MAX` if `ToastsView` is using the uncapped default.
+    pub fn count(&self, max_visible: usize) -> ReadSignal<usize> {
+        let toasts = self.toasts;
+        create_memo(move || {
+            toasts.with(|i| i.iter().filter(|(_, r)| *r == 0).take(max_visible).count())
+        })
     }
 }
 
+/// Implemented by toast payloads that carry a stable id, letting [`Toasts`] dismiss a specific
+/// entry without needing to match on the whole value.
+pub trait Identifiable {
+    fn id(&self) -> uuid::Uuid;
+}
+
+impl Identifiable for Toast {
+    fn id(&self) -> uuid::Uuid {
+        self.id
+    }
+}
+
+impl<T: Clone + Debug + Default + Serialize + DeserializeOwned + Identifiable> Toasts<T> {
+    /// Removes the toast with the given id from the underlying data, if one is present.
+    ///
+    /// This splices the signal directly, with no transition: `DefaultToastView` calls this only
+    /// *after* it has already played its own fade-out animation, which is what gives the close
+    /// button and the auto-dismiss timer their animated feel. Called directly (e.g. from
+    /// outside the view), the toast disappears immediately with no fade, since there's no view
+    /// instance here to animate.
+    pub fn dismiss(&self, id: uuid::Uuid) {
+        self.toasts.update(|i| i.retain(|(t, _)| t.id() != id));
+        self.persist();
+    }
+
+    /// Removes every toast currently tracked. Like [`Toasts::dismiss`], this is an immediate,
+    /// unanimated removal.
+    pub fn dismiss_all(&self) {
+        self.toasts.update(|i| i.clear());
+        self.persist();
+    }
+}
+
+/// Error returned when dispatching a toast before `ToastsView` has mounted and provided its
+/// `Toasts<Toast>` context.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DispatchError {
+    NotMounted,
+}
+
+impl std::fmt::Display for DispatchError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            DispatchError::NotMounted => {
+                write!(f, "no ToastsView has mounted to provide a Toasts<Toast> context yet")
+            }
+        }
+    }
+}
+
+impl std::error::Error for DispatchError {}
+
+/// Fire-and-forget entry point for raising a [`Toast`] from anywhere in scope, without having
+/// to thread a [`Toasts`] handle through props. Looks up the `Toasts<Toast>` that `ToastsView`
+/// provides via context, so it only works once a `ToastsView` has mounted somewhere above the
+/// caller in the component tree.
+pub struct ToastDispatcher;
+
+impl ToastDispatcher {
+    fn dispatch(toast: Toast) -> Result<(), DispatchError> {
+        try_use_context::<Toasts<Toast>>()
+            .ok_or(DispatchError::NotMounted)?
+            .add_toast(toast);
+        Ok(())
+    }
+
+    pub fn primary<T: ToString>(text: T) -> Result<(), DispatchError> {
+        Self::dispatch(Toast::primary(text))
+    }
+
+    pub fn success<T: ToString>(text: T) -> Result<(), DispatchError> {
+        Self::dispatch(Toast::success(text))
+    }
+
+    pub fn warning<T: ToString>(text: T) -> Result<(), DispatchError> {
+        Self::dispatch(Toast::warning(text))
+    }
+
+    pub fn danger<T: ToString>(text: T) -> Result<(), DispatchError> {
+        Self::dispatch(Toast::danger(text))
+    }
+}
+
+/// Where a [`ToastsView`] anchors its toast stack on screen.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum Placement {
+    TopLeft,
+    #[default]
+    TopCenter,
+    TopRight,
+    BottomLeft,
+    BottomCenter,
+    BottomRight,
+}
+
+impl Placement {
+    fn classes(self) -> &'static str {
+        match self {
+            Placement::TopLeft => "fixed top-14 left-14 flex flex-col items-start z-50",
+            Placement::TopCenter => "fixed top-14 flex flex-col items-center z-50",
+            Placement::TopRight => "fixed top-14 right-14 flex flex-col items-end z-50",
+            Placement::BottomLeft => "fixed bottom-14 left-14 flex flex-col items-start z-50",
+            Placement::BottomCenter => "fixed bottom-14 flex flex-col items-center z-50",
+            Placement::BottomRight => "fixed bottom-14 right-14 flex flex-col items-end z-50",
+        }
+    }
+
+    fn style(self) -> &'static str {
+        match self {
+            Placement::TopCenter | Placement::BottomCenter => {
+                "left: 50%; width: 500px; max-width: 100vw; transform: translateX(-50%);"
+            }
+            _ => "width: 500px; max-width: 100vw;",
+        }
+    }
+}
+
+/// `placement` defaults to [`Placement::TopCenter`] and `max_visible` defaults to no cap, so
+/// existing callers that don't set either keep the historical fixed top-center, show-everything
+/// behavior.
 #[component(inline_props)]
 pub fn ToastsView<
     G: Html,
@@ -138,10 +347,14 @@ pub fn ToastsView<
 >(
     view: F,
     toasts: Toasts<T>,
+    #[prop(default)] placement: Placement,
+    #[prop(default = usize::MAX)] max_visible: usize,
 ) -> View<G> {
     if try_use_context::<Toasts<T>>().is_none() {
         provide_context(toasts.clone());
     }
+    // Rank 0 toasts are eligible to show; only the oldest `max_visible` of them are rendered,
+    // the rest stay queued and promote into view as earlier ones are dismissed.
     let new_toasts = create_memo(move || {
         toasts
             .toasts
@@ -150,11 +363,12 @@ pub fn ToastsView<
             .filter(|(_, r)| *r == 0)
             .cloned()
             .map(|(t, _)| t)
+            .take(max_visible)
             .collect()
     });
     view! {
         div (class="-translate-y-[300px] z-50") // To include the right class for fading out toasts
-        div (class="fixed top-14 flex flex-col items-center z-50", style="left: 50%; width: 500px; max-width: 100vw; transform: translateX(-50%);") {
+        div (class=placement.classes(), style=placement.style()) {
             Indexed (
                 iterable=new_toasts,
                 view=view
@@ -167,31 +381,38 @@ pub fn ToastsView<
 pub fn DefaultToastView<G: Html>(toast: Toast) -> View<G> {
     let toast1 = toast.clone();
     let node_ref = create_node_ref();
-    let remove = move |_| {
+    let remove = move || {
         let toast1 = toast1.clone();
         spawn_local_scoped(async move {
             // Move to top
             node_ref.get::<DomNode>().add_class("-translate-y-[300px]");
             gloo_timers::future::TimeoutFuture::new(200).await;
             // Remove
-            let toasts = use_context::<Toasts<Toast>>();
-            toasts
-                .toasts
-                .update(|i| i.retain(|(t, _)| t.id != toast1.id));
+            use_context::<Toasts<Toast>>().dismiss(toast1.id);
         })
     };
 
-    // Spawn process to remove toast after 5 seconds
-    let toast1 = toast.clone();
-    spawn_local_scoped(async move {
-        gloo_timers::future::TimeoutFuture::new(5000).await;
-        // Move to top
-        node_ref.get::<DomNode>().add_class("-translate-y-[300px]");
-        gloo_timers::future::TimeoutFuture::new(200).await;
-        // Remove
-        let toasts = use_context::<Toasts<Toast>>();
-        toasts.toasts.update(|i| i.retain(|(t, _)| *t != toast1));
-    });
+    // Spawn process to remove toast after its configured duration, pausing the countdown
+    // while the user is hovering over it. `gloo_timers` futures can't be paused directly, so
+    // we tick in small increments and only accumulate elapsed time while not paused.
+    let paused = create_signal(false);
+    if !toast.sticky {
+        let toast1 = toast.clone();
+        spawn_local_scoped(async move {
+            let mut elapsed_ms = 0;
+            while elapsed_ms < toast1.duration_ms {
+                gloo_timers::future::TimeoutFuture::new(DURATION_TICK_MS).await;
+                if !paused.get() {
+                    elapsed_ms += DURATION_TICK_MS;
+                }
+            }
+            // Move to top
+            node_ref.get::<DomNode>().add_class("-translate-y-[300px]");
+            gloo_timers::future::TimeoutFuture::new(200).await;
+            // Remove
+            use_context::<Toasts<Toast>>().dismiss(toast1.id);
+        });
+    }
 
     let (bg_color, image_name) = match toast.toast_type {
         ToastType::Danger => ("#fc2828", "x_toast.png"),
@@ -200,7 +421,7 @@ pub fn DefaultToastView<G: Html>(toast: Toast) -> View<G> {
         ToastType::Success => ("#04c55e", "check_toast.png"),
     };
     view! {
-        div (ref=node_ref, style=format!("border-color: {}", bg_color), class="w-full bg-white max-w-lg px-5 py-4 m-2 border-[3px] rounded-xl flex flex-row items-center transition-all z-50") {
+        div (ref=node_ref, style=format!("border-color: {}", bg_color), class="w-full bg-white max-w-lg px-5 py-4 m-2 border-[3px] rounded-xl flex flex-row items-center transition-all z-50", on:mouseenter=move |_| paused.set(true), on:mouseleave=move |_| paused.set(false)) {
             // Icon
             img (src=(format!("/static/images/icons/{image_name}")), width="30px", height="30px", class="object-scale-down")
 
@@ -222,8 +443,23 @@ pub fn DefaultToastView<G: Html>(toast: Toast) -> View<G> {
 
             div (class="flex-grow")
 
+            // Action buttons
+            (View::new_fragment(
+                toast.actions.iter().cloned().map(|action| {
+                    let remove = remove.clone();
+                    view! {
+                        button (class="font-comfortaa font-bold hover:bg-slate-200 text-slate-700 hover:text-slate-900 px-3 py-2 mr-2 transition-all rounded-lg", on:click=move |_| {
+                            (action.callback)();
+                            remove();
+                        }) {
+                            (action.label.clone())
+                        }
+                    }
+                }).collect()
+            ))
+
             // Close button
-            button (class="font-comfortaa font-bold hover:bg-slate-200 text-2xl text-slate-500 hover:text-slate-900 w-10 h-10 mr-5 transition-all rounded-lg p-2", on:click=remove) {
+            button (class="font-comfortaa font-bold hover:bg-slate-200 text-2xl text-slate-500 hover:text-slate-900 w-10 h-10 mr-5 transition-all rounded-lg p-2", on:click=move |_| remove()) {
                 "X"
             }
         }
@@ -236,6 +472,8 @@ mod tests {
 
     #[test]
     fn test_toasts() {
+        // `placement`/`max_visible` are left unset to make sure existing callers still compile
+        // against their defaults.
         let _ = sycamore::render_to_string(|| {
             let toasts = Toasts::default();
             view! {
@@ -246,4 +484,75 @@ mod tests {
             }
         });
     }
+
+    #[test]
+    fn test_max_visible_truncates_rank_zero_fifo() {
+        create_root(|| {
+            let toasts: Toasts<Toast> = Toasts::default();
+            toasts.add_toast(Toast::primary("a"));
+            toasts.add_toast(Toast::primary("b"));
+            toasts.add_toast(Toast::primary("c"));
+
+            // Mirrors the filter + take that ToastsView's memo applies for a given max_visible.
+            let visible: Vec<_> = toasts
+                .toasts
+                .get_clone()
+                .into_iter()
+                .filter(|(_, r)| *r == 0)
+                .map(|(t, _)| t.title)
+                .take(2)
+                .collect();
+
+            assert_eq!(visible, vec!["a".to_string(), "b".to_string()]);
+        });
+    }
+
+    #[test]
+    fn test_dispatcher_errs_when_no_view_mounted() {
+        create_root(|| {
+            assert_eq!(ToastDispatcher::primary("hi"), Err(DispatchError::NotMounted));
+        });
+    }
+
+    #[test]
+    fn test_dismiss_removes_only_matching_toast() {
+        create_root(|| {
+            let toasts: Toasts<Toast> = Toasts::default();
+            toasts.add_toast(Toast::primary("a"));
+            let b = Toast::primary("b");
+            toasts.add_toast(b.clone());
+
+            toasts.dismiss(b.id());
+
+            let remaining = toasts.toasts.get_clone();
+            assert_eq!(remaining.len(), 1);
+            assert_eq!(remaining[0].0.title, "a");
+        });
+    }
+
+    #[test]
+    fn test_dismiss_all_clears_every_toast() {
+        create_root(|| {
+            let toasts: Toasts<Toast> = Toasts::default();
+            toasts.add_toast(Toast::primary("a"));
+            toasts.add_toast(Toast::primary("b"));
+
+            toasts.dismiss_all();
+
+            assert!(toasts.toasts.get_clone().is_empty());
+        });
+    }
+
+    #[test]
+    fn test_count_is_capped_by_max_visible() {
+        create_root(|| {
+            let toasts: Toasts<Toast> = Toasts::default();
+            toasts.add_toast(Toast::primary("a"));
+            toasts.add_toast(Toast::primary("b"));
+            toasts.add_toast(Toast::primary("c"));
+
+            assert_eq!(toasts.count(2).get(), 2);
+            assert_eq!(toasts.count(usize::MAX).get(), 3);
+        });
+    }
 }
@@ -0,0 +1,92 @@
+//! Pluggable persistence sinks for [`crate::Toasts`].
+
+use gloo_storage::Storage;
+use wasm_cookies::CookieOptions;
+
+const STORAGE_KEY: &str = "sycamore_toasts";
+
+/// Where a [`crate::Toasts`] instance persists its state between page loads.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
+pub enum Backend {
+    /// Stores toasts in a cookie, so they're sent on every HTTP request. Limited by the
+    /// browser's ~4KB per-cookie size.
+    Cookie,
+    /// Stores toasts in `localStorage`, which persists across tabs and browser restarts and
+    /// isn't sent to the server. This is the default backend: a bare `Toasts::default()` (or
+    /// any construction that doesn't pick a `Backend`) lands here, not in a cookie.
+    #[default]
+    LocalStorage,
+    /// Stores toasts in `sessionStorage`, which is cleared when the tab closes.
+    SessionStorage,
+}
+
+impl Backend {
+    pub(crate) fn storage(self) -> &'static dyn StorageBackend {
+        match self {
+            Backend::Cookie => &CookieBackend,
+            Backend::LocalStorage => &LocalStorageBackend,
+            Backend::SessionStorage => &SessionStorageBackend,
+        }
+    }
+}
+
+/// A sink that `Toasts<T>` can read/write its serialized state from/to.
+pub(crate) trait StorageBackend {
+    fn load(&self) -> Option<String>;
+    fn save(&self, value: &str);
+    fn clear(&self);
+}
+
+struct CookieBackend;
+
+impl StorageBackend for CookieBackend {
+    fn load(&self) -> Option<String> {
+        wasm_cookies::get(STORAGE_KEY).and_then(Result::ok)
+    }
+
+    fn save(&self, value: &str) {
+        wasm_cookies::set(STORAGE_KEY, value, &CookieOptions::default());
+    }
+
+    fn clear(&self) {
+        wasm_cookies::delete(STORAGE_KEY);
+    }
+}
+
+struct LocalStorageBackend;
+
+impl StorageBackend for LocalStorageBackend {
+    fn load(&self) -> Option<String> {
+        gloo_storage::LocalStorage::raw()
+            .get_item(STORAGE_KEY)
+            .ok()
+            .flatten()
+    }
+
+    fn save(&self, value: &str) {
+        let _ = gloo_storage::LocalStorage::raw().set_item(STORAGE_KEY, value);
+    }
+
+    fn clear(&self) {
+        let _ = gloo_storage::LocalStorage::raw().remove_item(STORAGE_KEY);
+    }
+}
+
+struct SessionStorageBackend;
+
+impl StorageBackend for SessionStorageBackend {
+    fn load(&self) -> Option<String> {
+        gloo_storage::SessionStorage::raw()
+            .get_item(STORAGE_KEY)
+            .ok()
+            .flatten()
+    }
+
+    fn save(&self, value: &str) {
+        let _ = gloo_storage::SessionStorage::raw().set_item(STORAGE_KEY, value);
+    }
+
+    fn clear(&self) {
+        let _ = gloo_storage::SessionStorage::raw().remove_item(STORAGE_KEY);
+    }
+}